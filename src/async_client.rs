@@ -0,0 +1,87 @@
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use reqwest::{Client, Response};
+
+use crate::{ApiResponse, WaveError, WaveMusic};
+
+const DEFAULT_BASE_URL: &str = "https://api.wireway.ch";
+
+/// An async counterpart to [`WaveClient`](crate::WaveClient), built on [`reqwest::Client`].
+///
+/// Available behind the `async` feature for applications that run inside an
+/// async runtime (Tokio, a web server, ...) and would otherwise need
+/// `spawn_blocking` to drive the blocking client. Thumbnail downloads are
+/// returned as a byte stream so large images can be piped to disk without
+/// buffering the whole response in memory.
+pub struct AsyncWaveClient {
+    client: Client,
+    base_url: String,
+}
+
+impl Default for AsyncWaveClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncWaveClient {
+    /// Creates a client targeting the public Wave API.
+    pub fn new() -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL)
+    }
+
+    /// Creates a client targeting a custom Wave API host, e.g. a self-hosted instance.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Searches the Wave API for music matching `q`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
+    pub async fn search(&self, q: &str) -> Result<Vec<WaveMusic>, WaveError> {
+        let url = format!("{}/wave/ytmusicsearch?q={}", self.base_url, q);
+        let response = Self::fetch_data(&self.client, &url).await?;
+        let text = response.text().await?;
+        let response_json: ApiResponse = serde_json::from_str(&text)?;
+        Ok(response_json.items)
+    }
+
+    /// Fetches the thumbnail image for a music item as a stream of byte chunks.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub async fn thumbnail(
+        &self,
+        item: &WaveMusic,
+    ) -> Result<impl Stream<Item = Result<Bytes, WaveError>>, WaveError> {
+        let id = item.id.as_ref().ok_or(WaveError::MissingId)?;
+        let url = format!("{}/wave/thumbnail/{}", self.base_url, id);
+        let response = Self::fetch_data(&self.client, &url).await?;
+        Ok(response.bytes_stream().map(|chunk| chunk.map_err(WaveError::from)))
+    }
+
+    /// Sends a GET request and checks the response status.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response status is not successful.
+    async fn fetch_data(client: &Client, url: &str) -> Result<Response, WaveError> {
+        let response = client.get(url).send().await?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else if status.as_u16() == 404 {
+            Err(WaveError::NotFound)
+        } else {
+            Err(WaveError::Http {
+                status: status.as_u16(),
+            })
+        }
+    }
+}
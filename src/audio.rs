@@ -0,0 +1,57 @@
+/// Audio encoding formats offered by the Wave API's audio endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    /// Opus audio (`.opus`), typically the smallest file for a given quality.
+    Opus,
+    /// AAC audio in an M4A container (`.m4a`).
+    M4a,
+    /// MP3 audio (`.mp3`).
+    Mp3,
+}
+
+impl AudioFormat {
+    /// The value this format is sent as in the `format` query parameter.
+    pub(crate) fn as_query_value(self) -> &'static str {
+        match self {
+            AudioFormat::Opus => "opus",
+            AudioFormat::M4a => "m4a",
+            AudioFormat::Mp3 => "mp3",
+        }
+    }
+
+    /// The conventional file extension for this format, without a leading dot.
+    pub fn extension(self) -> &'static str {
+        self.as_query_value()
+    }
+}
+
+/// An audio download, pairing the HTTP response body with resolved metadata.
+///
+/// Read `response` (e.g. with `std::io::copy`) to stream the audio to disk;
+/// `content_length` and `mime_type` are resolved up front so callers can show
+/// progress and pick a file extension before the body is fully read.
+pub struct AudioDownload {
+    /// The underlying HTTP response body.
+    pub response: reqwest::blocking::Response,
+    /// The size of the audio in bytes, if the server reported a `Content-Length`.
+    pub content_length: Option<u64>,
+    /// The MIME type of the audio, if the server reported a `Content-Type`.
+    pub mime_type: Option<String>,
+}
+
+impl AudioDownload {
+    pub(crate) fn from_response(response: reqwest::blocking::Response) -> Self {
+        let content_length = response.content_length();
+        let mime_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        Self {
+            response,
+            content_length,
+            mime_type,
+        }
+    }
+}
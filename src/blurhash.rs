@@ -0,0 +1,134 @@
+const BASE83_CHARS: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Encodes an RGB8 pixel buffer into a BlurHash string.
+///
+/// `x_components` and `y_components` (each expected to already be validated
+/// as `1..=9`) control how many cosine basis functions are sampled along
+/// each axis; `pixels` must be `width * height * 3` bytes of row-major RGB8
+/// data.
+pub(crate) fn encode(x_components: u32, y_components: u32, width: u32, height: u32, pixels: &[u8]) -> String {
+    let width = width as usize;
+    let height = height as usize;
+
+    let mut factors = Vec::with_capacity((x_components * y_components) as usize);
+    for j in 0..y_components {
+        for i in 0..x_components {
+            factors.push(multiply_basis_function(i, j, width, height, pixels));
+        }
+    }
+
+    let dc = factors[0];
+    let ac = &factors[1..];
+
+    let mut result = String::new();
+
+    let size_flag = (x_components - 1) + (y_components - 1) * 9;
+    result.push_str(&encode_base83(size_flag as u64, 1));
+
+    let max_value = if ac.is_empty() {
+        result.push_str(&encode_base83(0, 1));
+        1.0
+    } else {
+        let actual_max = ac
+            .iter()
+            .flat_map(|&(r, g, b)| [r.abs(), g.abs(), b.abs()])
+            .fold(0.0_f64, f64::max);
+        let quantized_max = ((actual_max * 166.0 - 0.5).floor() as i64).clamp(0, 82) as u64;
+        result.push_str(&encode_base83(quantized_max, 1));
+        (quantized_max as f64 + 1.0) / 166.0
+    };
+
+    result.push_str(&encode_base83(encode_dc(dc), 4));
+
+    for &ac_value in ac {
+        result.push_str(&encode_base83(encode_ac(ac_value, max_value), 2));
+    }
+
+    result
+}
+
+/// Computes one `(i, j)` color component by summing the cosine basis function over every pixel.
+fn multiply_basis_function(i: u32, j: u32, width: usize, height: usize, pixels: &[u8]) -> (f64, f64, f64) {
+    let mut r = 0.0;
+    let mut g = 0.0;
+    let mut b = 0.0;
+    let normalisation = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+
+    for y in 0..height {
+        for x in 0..width {
+            let basis = (std::f64::consts::PI * i as f64 * x as f64 / width as f64).cos()
+                * (std::f64::consts::PI * j as f64 * y as f64 / height as f64).cos();
+            let idx = (y * width + x) * 3;
+            r += basis * srgb_to_linear(pixels[idx]);
+            g += basis * srgb_to_linear(pixels[idx + 1]);
+            b += basis * srgb_to_linear(pixels[idx + 2]);
+        }
+    }
+
+    let scale = normalisation / (width * height) as f64;
+    (r * scale, g * scale, b * scale)
+}
+
+fn encode_dc((r, g, b): (f64, f64, f64)) -> u64 {
+    let r = linear_to_srgb(r) as u64;
+    let g = linear_to_srgb(g) as u64;
+    let b = linear_to_srgb(b) as u64;
+    (r << 16) | (g << 8) | b
+}
+
+fn encode_ac((r, g, b): (f64, f64, f64), max_value: f64) -> u64 {
+    let quantize = |value: f64| -> u64 {
+        let normalized = (value / max_value).clamp(-1.0, 1.0);
+        ((normalized.signum() * normalized.abs().powf(0.5) * 9.0 + 9.5).floor() as i64).clamp(0, 18) as u64
+    };
+    quantize(r) * 19 * 19 + quantize(g) * 19 + quantize(b)
+}
+
+fn encode_base83(value: u64, length: usize) -> String {
+    let mut result = vec![0u8; length];
+    let mut value = value;
+    for slot in result.iter_mut().rev() {
+        *slot = BASE83_CHARS[(value % 83) as usize];
+        value /= 83;
+    }
+    String::from_utf8(result).expect("base83 alphabet is ASCII")
+}
+
+fn srgb_to_linear(value: u8) -> f64 {
+    let v = value as f64 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let srgb = if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2x2 image: red, green / blue, white, encoded against an independent
+    // reference implementation of the canonical BlurHash algorithm.
+    const PIXELS: [u8; 12] = [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+
+    #[test]
+    fn matches_reference_encoder_with_ac_components() {
+        assert_eq!(encode(2, 2, 2, 2, &PIXELS), "A~Lqe9|l~h|c");
+    }
+
+    #[test]
+    fn matches_reference_encoder_with_dc_only() {
+        assert_eq!(encode(1, 1, 2, 2, &PIXELS), "00Lqe9");
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A pluggable cache consulted by [`WaveClient`](crate::WaveClient) before
+/// hitting the network.
+///
+/// Implement this to back search results and thumbnail bytes with your own
+/// storage (in-memory, a database, ...); [`FsCache`] provides a default
+/// filesystem-backed implementation.
+pub trait Cache: Send + Sync {
+    /// Looks up a cached value by key, returning `None` on a miss or an expired entry.
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+    /// Stores `value` under `key`, to be considered stale after `ttl`.
+    fn set(&self, key: &str, value: &[u8], ttl: Duration);
+}
+
+/// A filesystem-backed [`Cache`] keyed by query string / track `id`.
+///
+/// Entries are stored as one file per key under the configured directory,
+/// prefixed with the expiry timestamp so a TTL check doesn't need a second
+/// file for metadata.
+pub struct FsCache {
+    dir: PathBuf,
+}
+
+impl FsCache {
+    /// Creates a cache rooted at `dir`, creating the directory if it doesn't already exist.
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        self.dir.join(format!("{:x}.cache", hasher.finish()))
+    }
+}
+
+impl Cache for FsCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let bytes = std::fs::read(self.path_for(key)).ok()?;
+        let expires_at = u64::from_le_bytes(bytes.get(..8)?.try_into().ok()?);
+        if now_unix_millis() >= expires_at {
+            return None;
+        }
+        Some(bytes[8..].to_vec())
+    }
+
+    fn set(&self, key: &str, value: &[u8], ttl: Duration) {
+        let expires_at = now_unix_millis().saturating_add(ttl.as_millis() as u64);
+        let mut bytes = expires_at.to_le_bytes().to_vec();
+        bytes.extend_from_slice(value);
+        let _ = std::fs::write(self.path_for(key), bytes);
+    }
+}
+
+fn now_unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> FsCache {
+        let dir = std::env::temp_dir().join(format!("wirewave-fscache-test-{}-{}", name, std::process::id()));
+        FsCache::new(dir).unwrap()
+    }
+
+    #[test]
+    fn round_trips_a_cached_value() {
+        let cache = temp_cache("hit");
+        cache.set("key", b"hello", Duration::from_secs(60));
+        assert_eq!(cache.get("key"), Some(b"hello".to_vec()));
+    }
+
+    #[test]
+    fn misses_an_unknown_key() {
+        let cache = temp_cache("miss");
+        assert_eq!(cache.get("never-set"), None);
+    }
+
+    #[test]
+    fn expires_entries_once_the_ttl_has_elapsed() {
+        let cache = temp_cache("ttl");
+        cache.set("key", b"hello", Duration::from_millis(0));
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(cache.get("key"), None);
+    }
+
+    #[test]
+    fn different_keys_hash_to_different_paths() {
+        let cache = temp_cache("keying");
+        assert_ne!(cache.path_for("a"), cache.path_for("b"));
+    }
+}
@@ -0,0 +1,305 @@
+use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
+use reqwest::blocking::{Client, Response as ReqwestResponse};
+use std::time::Duration;
+
+use crate::audio::{AudioDownload, AudioFormat};
+use crate::cache::Cache;
+use crate::lyrics::{Lyrics, LyricsResponse};
+use crate::search::{SearchPage, SearchQuery};
+use crate::{blurhash, ApiResponse, WaveError, WaveMusic};
+
+const DEFAULT_BASE_URL: &str = "https://api.wireway.ch";
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_AUDIO_FORMAT: AudioFormat = AudioFormat::Opus;
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(3600);
+
+/// A reusable client for the Wave API.
+///
+/// `WaveClient` owns a pooled [`reqwest::blocking::Client`] so repeated
+/// searches and thumbnail downloads reuse the same connections instead of
+/// paying for a fresh TCP/TLS handshake on every call. Construct one with
+/// [`WaveClient::new`] and keep it around for the lifetime of your
+/// application, or point it at a self-hosted Wave instance with
+/// [`WaveClient::with_base_url`]. Attach a [`Cache`] with
+/// [`WaveClient::with_cache`] to avoid re-hitting the API for repeated
+/// searches and thumbnail lookups.
+pub struct WaveClient {
+    client: Client,
+    base_url: String,
+    cache: Option<Box<dyn Cache>>,
+    cache_ttl: Duration,
+}
+
+impl Default for WaveClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WaveClient {
+    /// Creates a client targeting the public Wave API with sane default timeouts.
+    pub fn new() -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL)
+    }
+
+    /// Creates a client targeting a custom Wave API host, e.g. a self-hosted instance.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+            .build()
+            .expect("failed to build reqwest client");
+
+        Self {
+            client,
+            base_url: base_url.into(),
+            cache: None,
+            cache_ttl: DEFAULT_CACHE_TTL,
+        }
+    }
+
+    /// Attaches a [`Cache`] that search results and thumbnail bytes are read from and written to.
+    pub fn with_cache(mut self, cache: impl Cache + 'static) -> Self {
+        self.cache = Some(Box::new(cache));
+        self
+    }
+
+    /// Sets how long cached entries stay fresh. Defaults to one hour.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Removes any attached cache, forcing every call to hit the network.
+    pub fn no_cache(mut self) -> Self {
+        self.cache = None;
+        self
+    }
+
+    /// Searches the Wave API for music matching `q`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
+    pub fn search(&self, q: &str) -> Result<Vec<WaveMusic>, WaveError> {
+        let url = format!(
+            "{}/wave/ytmusicsearch?q={}",
+            self.base_url,
+            percent_encode(q.as_bytes(), NON_ALPHANUMERIC)
+        );
+        let cache_key = format!("search:{}", url);
+        let response_json = self.fetch_and_cache(&url, &cache_key)?;
+        Ok(response_json.items)
+    }
+
+    /// Fetches the thumbnail image data for a music item.
+    ///
+    /// This streams the response body directly and does not consult the
+    /// cache; see [`WaveClient::thumbnail_bytes`] for a cached, buffered
+    /// alternative.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub fn thumbnail(&self, item: &WaveMusic) -> Result<ReqwestResponse, WaveError> {
+        let id = item.id.as_ref().ok_or(WaveError::MissingId)?;
+        let url = format!("{}/wave/thumbnail/{}", self.base_url, id);
+        Self::fetch_data(&self.client, &url)
+    }
+
+    /// Fetches the thumbnail image for a music item as bytes, consulting the cache if one is attached.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub fn thumbnail_bytes(&self, item: &WaveMusic) -> Result<Vec<u8>, WaveError> {
+        let id = item.id.as_ref().ok_or(WaveError::MissingId)?;
+        let cache_key = format!("thumbnail:{}", id);
+
+        if let Some(cache) = self.cache.as_deref() {
+            if let Some(cached) = cache.get(&cache_key) {
+                return Ok(cached);
+            }
+        }
+
+        let response = self.thumbnail(item)?;
+        let bytes = response.bytes()?.to_vec();
+
+        if let Some(cache) = self.cache.as_deref() {
+            cache.set(&cache_key, &bytes, self.cache_ttl);
+        }
+
+        Ok(bytes)
+    }
+
+    /// Generates a compact [BlurHash](https://blurha.sh) placeholder string from a music item's thumbnail.
+    ///
+    /// `x_components` and `y_components` (each `1..=9`) control how many
+    /// cosine basis functions are sampled along each axis of the image;
+    /// higher values capture more detail at the cost of a longer string.
+    /// Goes through [`WaveClient::thumbnail_bytes`], so it benefits from an
+    /// attached cache.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `x_components` or
+    /// `y_components` is outside `1..=9`, if the thumbnail cannot be
+    /// fetched, or if the image data cannot be decoded.
+    pub fn thumbnail_blurhash(
+        &self,
+        item: &WaveMusic,
+        x_components: u32,
+        y_components: u32,
+    ) -> Result<String, WaveError> {
+        if !(1..=9).contains(&x_components) || !(1..=9).contains(&y_components) {
+            return Err(WaveError::InvalidComponents);
+        }
+
+        let bytes = self.thumbnail_bytes(item)?;
+        let image = image::load_from_memory(&bytes)?.into_rgb8();
+        let (width, height) = image.dimensions();
+
+        Ok(blurhash::encode(x_components, y_components, width, height, image.as_raw()))
+    }
+
+    /// Fetches plain or time-synced lyrics for a music item.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID, the HTTP request fails, or the response cannot be parsed.
+    pub fn lyrics(&self, item: &WaveMusic) -> Result<Lyrics, WaveError> {
+        let id = item.id.as_ref().ok_or(WaveError::MissingId)?;
+        let url = format!("{}/wave/lyrics/{}", self.base_url, id);
+        let response = Self::fetch_data(&self.client, &url)?;
+        let response_json: LyricsResponse = Self::parse_response(response)?;
+        Ok(Lyrics::parse(&response_json.lyrics))
+    }
+
+    /// Searches the Wave API with explicit paging control.
+    ///
+    /// Unlike [`WaveClient::search`], this forwards `limit`/`offset` to the
+    /// API and returns paging metadata (`total`/`next_offset`) alongside the
+    /// results, so callers can implement infinite scroll.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
+    pub fn search_paged(&self, query: SearchQuery) -> Result<SearchPage, WaveError> {
+        let mut url = format!(
+            "{}/wave/ytmusicsearch?q={}",
+            self.base_url,
+            percent_encode(query.q.as_bytes(), NON_ALPHANUMERIC)
+        );
+        if let Some(limit) = query.limit {
+            url.push_str(&format!("&limit={}", limit));
+        }
+        if let Some(offset) = query.offset {
+            url.push_str(&format!("&offset={}", offset));
+        }
+        let cache_key = format!("search:{}", url);
+        let response_json = self.fetch_and_cache(&url, &cache_key)?;
+
+        Ok(SearchPage {
+            items: response_json.items,
+            total: response_json.total,
+            next_offset: response_json.next_offset,
+        })
+    }
+
+    /// Streams the audio for a music item in the default format (Opus).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub fn stream(&self, item: &WaveMusic) -> Result<AudioDownload, WaveError> {
+        self.download(item, DEFAULT_AUDIO_FORMAT)
+    }
+
+    /// Downloads the audio for a music item in the requested format.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub fn download(&self, item: &WaveMusic, format: AudioFormat) -> Result<AudioDownload, WaveError> {
+        let id = item.id.as_ref().ok_or(WaveError::MissingId)?;
+        let url = format!(
+            "{}/wave/audio/{}?format={}",
+            self.base_url,
+            id,
+            format.as_query_value()
+        );
+        let response = Self::fetch_data(&self.client, &url)?;
+        Ok(AudioDownload::from_response(response))
+    }
+
+    /// Fetches data from the specified URL using the given HTTP client.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response status is not successful.
+    pub(crate) fn fetch_data(client: &Client, url: &str) -> Result<ReqwestResponse, WaveError> {
+        let response = client.get(url).send()?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else if status.as_u16() == 404 {
+            Err(WaveError::NotFound)
+        } else {
+            Err(WaveError::Http {
+                status: status.as_u16(),
+            })
+        }
+    }
+
+    /// Parses the response body as JSON into `T`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the response body cannot be parsed as JSON.
+    pub(crate) fn parse_response<T: serde::de::DeserializeOwned>(
+        response: ReqwestResponse,
+    ) -> Result<T, WaveError> {
+        let text = response.text()?;
+        Self::parse_text(&text)
+    }
+
+    /// Parses a JSON string into `T`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `text` cannot be parsed as JSON.
+    fn parse_text<T: serde::de::DeserializeOwned>(text: &str) -> Result<T, WaveError> {
+        let parsed = serde_json::from_str(text)?;
+        Ok(parsed)
+    }
+
+    /// Fetches and parses a search response, consulting (and populating) the cache first.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
+    fn fetch_and_cache(&self, url: &str, cache_key: &str) -> Result<ApiResponse, WaveError> {
+        if let Some(text) = self.cache_get_text(cache_key) {
+            if let Ok(parsed) = Self::parse_text(&text) {
+                return Ok(parsed);
+            }
+        }
+
+        let response = Self::fetch_data(&self.client, url)?;
+        let text = response.text()?;
+        self.cache_set_text(cache_key, &text);
+        Self::parse_text(&text)
+    }
+
+    /// Reads a cached UTF-8 text value, if a cache is attached and the key is present.
+    fn cache_get_text(&self, key: &str) -> Option<String> {
+        let cache = self.cache.as_deref()?;
+        String::from_utf8(cache.get(key)?).ok()
+    }
+
+    /// Writes a text value to the cache, if one is attached.
+    fn cache_set_text(&self, key: &str, text: &str) {
+        if let Some(cache) = self.cache.as_deref() {
+            cache.set(key, text.as_bytes(), self.cache_ttl);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+/// Errors that can occur while talking to the Wave API.
+#[derive(Error, Debug)]
+pub enum WaveError {
+    /// The Wave API responded with a non-success status code other than 404
+    /// (a 404 is reported as [`WaveError::NotFound`]).
+    #[error("Wave API returned HTTP {status}")]
+    Http {
+        /// The HTTP status code returned by the Wave API.
+        status: u16,
+    },
+    /// The underlying HTTP request failed (network error, timeout, etc.).
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    /// The response body could not be parsed as JSON.
+    #[error("failed to parse response: {0}")]
+    Parse(#[from] serde_json::Error),
+    /// The operation requires a music item with an `id`, but it has none.
+    #[error("music item does not have an ID")]
+    MissingId,
+    /// The requested resource does not exist on the Wave API.
+    #[error("not found")]
+    NotFound,
+    /// The thumbnail image data could not be decoded.
+    #[error("failed to decode thumbnail image: {0}")]
+    Image(#[from] image::ImageError),
+    /// `x_components` / `y_components` were outside the BlurHash-mandated `1..=9` range.
+    #[error("x_components and y_components must each be between 1 and 9")]
+    InvalidComponents,
+}
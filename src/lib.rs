@@ -1,10 +1,29 @@
 //! # WireWave
-//!  
+//!
 //! Use the [Wave API](https://wireway.ch) to fetch music by search query and retrieve thumbnails.
 
-use reqwest::blocking::{Client, Response as ReqwestResponse};
+mod audio;
+mod blurhash;
+mod cache;
+mod client;
+mod error;
+mod lyrics;
+mod search;
+
+#[cfg(feature = "async")]
+mod async_client;
+
+pub use audio::{AudioDownload, AudioFormat};
+pub use cache::{Cache, FsCache};
+pub use client::WaveClient;
+pub use error::WaveError;
+pub use lyrics::{LyricLine, Lyrics};
+pub use search::{SearchPage, SearchQuery};
+
+#[cfg(feature = "async")]
+pub use async_client::AsyncWaveClient;
+
 use serde::{Deserialize, Serialize};
-use std::error::Error;
 use std::fmt;
 
 /// Represents a music item retrieved from the Wave API.
@@ -26,9 +45,15 @@ pub struct WaveMusic {
 
 /// Represents the response structure from the Wave API.
 #[derive(Serialize, Deserialize, Debug)]
-struct ApiResponse {
+pub(crate) struct ApiResponse {
     /// A list of music items.
     items: Vec<WaveMusic>,
+    /// The total number of results available for the query, if the API reports it.
+    #[serde(default)]
+    total: Option<u32>,
+    /// The offset to request for the next page, if the API reports one.
+    #[serde(rename = "nextOffset", default)]
+    next_offset: Option<u32>,
 }
 
 impl fmt::Display for WaveMusic {
@@ -45,13 +70,19 @@ impl fmt::Display for WaveMusic {
 impl WaveMusic {
     /// Creates a new WaveMusic instance by querying the Wave API with the specified search term.
     ///
+    /// This is a thin wrapper around [`WaveClient::search`] that builds a
+    /// one-off client; prefer constructing a [`WaveClient`] yourself and
+    /// reusing it across calls so the connection pool is shared.
+    ///
     /// # Arguments
     ///
     /// * `q` - A string slice that holds the search term.
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
+    /// use wirewave::*;
+    ///
     /// let music_items = WaveMusic::new("example search term".to_string()).unwrap();
     /// for item in music_items {
     ///     println!("{}", item);
@@ -61,19 +92,32 @@ impl WaveMusic {
     /// # Errors
     ///
     /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
-    pub fn new(q: String) -> Result<Vec<WaveMusic>, Box<dyn Error>> {
-        let url = format!("https://api.wireway.ch/wave/ytmusicsearch?q={}", q);
-        let client = Client::new();
-        let response = Self::fetch_data(&client, &url)?;
-        let response_json: ApiResponse = Self::parse_response(response)?;
-        Ok(response_json.items)
+    pub fn new(q: String) -> Result<Vec<WaveMusic>, WaveError> {
+        WaveClient::new().search(&q)
+    }
+
+    /// Searches the Wave API with explicit paging control.
+    ///
+    /// This is a thin wrapper around [`WaveClient::search_paged`] that
+    /// builds a one-off client; prefer reusing a [`WaveClient`] when paging
+    /// through several result pages.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
+    pub fn search_paged(query: SearchQuery) -> Result<SearchPage, WaveError> {
+        WaveClient::new().search_paged(query)
     }
 
     /// Fetches the thumbnail image data for the music item.
     ///
+    /// This is a thin wrapper around [`WaveClient::thumbnail`] that builds a
+    /// one-off client; prefer reusing a [`WaveClient`] for batch thumbnail
+    /// downloads so they share one connection pool.
+    ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use std::fs::File;
     /// use std::io::copy;
     /// use std::path::Path;
@@ -93,53 +137,82 @@ impl WaveMusic {
     /// # Errors
     ///
     /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
-    pub fn thumbnail(&self) -> Result<reqwest::blocking::Response, Box<dyn Error>> {
-        // Ensure the music item has an ID
-        let id = self.id.as_ref().ok_or("Music item does not have an ID")?;
-        // Construct the thumbnail URL
-        let url = format!("https://api.wireway.ch/wave/thumbnail/{}", id);
-
-        let response = reqwest::blocking::get(&url)?;
-
-        // Check for response errors
-        if response.status().is_success() {
-            Ok(response)
-        } else {
-            Err(format!("Failed to fetch thumbnail: HTTP {}", response.status()).into())
-        }
+    pub fn thumbnail(&self) -> Result<reqwest::blocking::Response, WaveError> {
+        WaveClient::new().thumbnail(self)
     }
 
-    /// Fetches data from the specified URL using the given HTTP client.
+    /// Fetches plain or time-synced lyrics for the music item.
     ///
-    /// # Arguments
+    /// This is a thin wrapper around [`WaveClient::lyrics`] that builds a
+    /// one-off client; prefer reusing a [`WaveClient`] for repeated lookups.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID, the HTTP request fails, or the response cannot be parsed.
+    pub fn lyrics(&self) -> Result<Lyrics, WaveError> {
+        WaveClient::new().lyrics(self)
+    }
+
+    /// Streams the audio for the music item in the default format (Opus).
     ///
-    /// * `client` - A reference to the HTTP client.
-    /// * `url` - A string slice that holds the URL to fetch data from.
+    /// This is a thin wrapper around [`WaveClient::stream`] that builds a one-off client.
     ///
     /// # Errors
     ///
-    /// This function will return an error if the HTTP request fails or if the response status is not successful.
-    fn fetch_data(client: &Client, url: &str) -> Result<ReqwestResponse, Box<dyn Error>> {
-        let response = client.get(url).send()?;
-        if response.status().is_success() {
-            Ok(response)
-        } else {
-            Err(format!("Failed to fetch data: HTTP {}", response.status()).into())
-        }
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub fn stream(&self) -> Result<AudioDownload, WaveError> {
+        WaveClient::new().stream(self)
     }
 
-    /// Parses the response body into the ApiResponse structure.
+    /// Downloads the audio for the music item in the requested format.
     ///
-    /// # Arguments
+    /// This is a thin wrapper around [`WaveClient::download`] that builds a one-off client.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    pub fn download(&self, format: AudioFormat) -> Result<AudioDownload, WaveError> {
+        WaveClient::new().download(self, format)
+    }
+
+    /// Generates a compact [BlurHash](https://blurha.sh) placeholder string from the music item's thumbnail.
+    ///
+    /// This is a thin wrapper around [`WaveClient::thumbnail_blurhash`] that
+    /// builds a one-off client; prefer reusing a [`WaveClient`] to honor a
+    /// custom base URL or attached cache.
+    ///
+    /// # Errors
     ///
-    /// * `response` - The HTTP response to parse.
+    /// This function will return an error if `x_components` or
+    /// `y_components` is outside `1..=9`, if the thumbnail cannot be
+    /// fetched, or if the image data cannot be decoded.
+    pub fn thumbnail_blurhash(&self, x_components: u32, y_components: u32) -> Result<String, WaveError> {
+        WaveClient::new().thumbnail_blurhash(self, x_components, y_components)
+    }
+
+    /// Async counterpart to [`WaveMusic::new`], built on [`AsyncWaveClient`].
+    ///
+    /// Requires the `async` feature.
     ///
     /// # Errors
     ///
-    /// This function will return an error if the response body cannot be parsed as JSON.
-    fn parse_response(response: ReqwestResponse) -> Result<ApiResponse, Box<dyn Error>> {
-        let text = response.text()?;
-        let response_json: ApiResponse = serde_json::from_str(&text)?;
-        Ok(response_json)
+    /// This function will return an error if the HTTP request fails or if the response cannot be parsed.
+    #[cfg(feature = "async")]
+    pub async fn new_async(q: String) -> Result<Vec<WaveMusic>, WaveError> {
+        AsyncWaveClient::new().search(&q).await
+    }
+
+    /// Async counterpart to [`WaveMusic::thumbnail`], returning the image as a byte stream.
+    ///
+    /// Requires the `async` feature.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the music item does not have an ID or if the HTTP request fails.
+    #[cfg(feature = "async")]
+    pub async fn thumbnail_async(
+        &self,
+    ) -> Result<impl futures_util::Stream<Item = Result<bytes::Bytes, WaveError>>, WaveError> {
+        AsyncWaveClient::new().thumbnail(self).await
     }
 }
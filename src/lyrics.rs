@@ -0,0 +1,117 @@
+use serde::Deserialize;
+
+/// Raw lyrics payload returned by the Wave API.
+#[derive(Deserialize, Debug)]
+pub(crate) struct LyricsResponse {
+    pub(crate) lyrics: String,
+}
+
+/// A single time-synced lyric line, as parsed from an LRC-style `[mm:ss.xx]` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricLine {
+    /// The playback position, in milliseconds, at which this line becomes active.
+    pub timestamp_ms: u32,
+    /// The lyric text for this line.
+    pub text: String,
+}
+
+/// Lyrics for a [`WaveMusic`](crate::WaveMusic) item.
+#[derive(Debug, Clone)]
+pub enum Lyrics {
+    /// Time-synced lyrics, sorted by `timestamp_ms`.
+    Synced(Vec<LyricLine>),
+    /// Plain, unsynchronized lyric text.
+    Plain(String),
+}
+
+impl Lyrics {
+    /// Parses an LRC-style lyrics payload.
+    ///
+    /// Lines matching a `[mm:ss.xx]` tag are collected as [`LyricLine`]s and
+    /// sorted by timestamp; if none of the lines carry a recognizable tag,
+    /// the raw text is kept as-is in [`Lyrics::Plain`].
+    pub(crate) fn parse(raw: &str) -> Lyrics {
+        let mut lines: Vec<LyricLine> = raw.lines().filter_map(parse_lrc_line).collect();
+        if lines.is_empty() {
+            Lyrics::Plain(raw.to_string())
+        } else {
+            lines.sort_by_key(|line| line.timestamp_ms);
+            Lyrics::Synced(lines)
+        }
+    }
+
+    /// Looks up the lyric line active at `position_ms`, via binary search over the sorted timestamps.
+    ///
+    /// Returns `None` for [`Lyrics::Plain`], or if `position_ms` is before the first line.
+    pub fn active_line(&self, position_ms: u32) -> Option<&LyricLine> {
+        let Lyrics::Synced(lines) = self else {
+            return None;
+        };
+        let idx = match lines.binary_search_by_key(&position_ms, |line| line.timestamp_ms) {
+            Ok(idx) => idx,
+            Err(0) => return None,
+            Err(idx) => idx - 1,
+        };
+        lines.get(idx)
+    }
+}
+
+/// Parses a single LRC line (`[mm:ss.xx]text`) into a [`LyricLine`].
+fn parse_lrc_line(line: &str) -> Option<LyricLine> {
+    let line = line.trim();
+    let rest = line.strip_prefix('[')?;
+    let (tag, text) = rest.split_once(']')?;
+
+    let (minutes, tag) = tag.split_once(':')?;
+    let (seconds, centiseconds) = tag.split_once('.').unwrap_or((tag, "0"));
+
+    let minutes: u32 = minutes.parse().ok()?;
+    let seconds: u32 = seconds.parse().ok()?;
+    let centiseconds: u32 = centiseconds.parse().ok()?;
+
+    let timestamp_ms = (minutes * 60 + seconds) * 1000 + centiseconds * 10;
+    Some(LyricLine {
+        timestamp_ms,
+        text: text.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_synced_lines_sorted_by_timestamp() {
+        let raw = "[00:05.50]second line\n[00:00.00]first line";
+        let lyrics = Lyrics::parse(raw);
+
+        let Lyrics::Synced(lines) = lyrics else {
+            panic!("expected synced lyrics");
+        };
+        assert_eq!(
+            lines,
+            vec![
+                LyricLine { timestamp_ms: 0, text: "first line".to_string() },
+                LyricLine { timestamp_ms: 5500, text: "second line".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_plain_text_without_any_tags() {
+        let raw = "just some\nunsynced lyrics";
+        let lyrics = Lyrics::parse(raw);
+
+        assert!(matches!(lyrics, Lyrics::Plain(text) if text == raw));
+    }
+
+    #[test]
+    fn active_line_binary_searches_for_the_current_position() {
+        let lyrics = Lyrics::parse("[00:00.00]first\n[00:10.00]second\n[00:20.00]third");
+
+        assert_eq!(lyrics.active_line(0).unwrap().text, "first");
+        assert_eq!(lyrics.active_line(15_000).unwrap().text, "second");
+        assert_eq!(lyrics.active_line(25_000).unwrap().text, "third");
+        assert!(Lyrics::parse("no tags here").active_line(0).is_none());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::WaveMusic;
+
+/// A paginated search query against the Wave API.
+///
+/// Build one with [`SearchQuery::new`] and pass it to
+/// [`WaveClient::search_paged`](crate::WaveClient::search_paged) to control
+/// how many results come back and where the next page starts.
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+    pub(crate) q: String,
+    pub(crate) limit: Option<u32>,
+    pub(crate) offset: Option<u32>,
+}
+
+impl SearchQuery {
+    /// Creates a search query for `q` with no limit or offset.
+    pub fn new(q: impl Into<String>) -> Self {
+        Self {
+            q: q.into(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Caps the number of results returned.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the first `offset` results, for paging through a result set.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// A page of search results, with metadata for fetching subsequent pages.
+#[derive(Debug)]
+pub struct SearchPage {
+    /// The music items returned for this page.
+    pub items: Vec<WaveMusic>,
+    /// The total number of results available for the query, if the API reports it.
+    pub total: Option<u32>,
+    /// The offset to request for the next page, if more results remain.
+    pub next_offset: Option<u32>,
+}